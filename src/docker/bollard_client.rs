@@ -0,0 +1,221 @@
+use super::{Container, DockerClient, DockerError};
+use crate::registry::{self, UpdateStatus};
+use bollard::container::{
+    InspectContainerOptions, ListContainersOptions, LogsOptions, RemoveContainerOptions,
+    StatsOptions, StopContainerOptions, TopOptions,
+};
+use bollard::image::CreateImageOptions;
+use bollard::Docker;
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+
+/// `DockerClient` backed by the Docker Engine API.
+pub struct BollardClient {
+    docker: Docker,
+}
+
+impl BollardClient {
+    /// Connects using the same defaults as the `docker` CLI (`DOCKER_HOST`,
+    /// falling back to the local Unix socket / named pipe).
+    pub fn connect() -> Result<Self, DockerError> {
+        let docker =
+            Docker::connect_with_local_defaults().map_err(|e| DockerError::Connection(e.to_string()))?;
+        Ok(Self { docker })
+    }
+}
+
+#[async_trait::async_trait]
+impl DockerClient for BollardClient {
+    async fn list_containers(&self, all: bool) -> Result<Vec<Container>, DockerError> {
+        let options = ListContainersOptions::<String> {
+            all,
+            ..Default::default()
+        };
+        let summaries = self.docker.list_containers(Some(options)).await?;
+        Ok(summaries.into_iter().map(container_from_summary).collect())
+    }
+
+    async fn stop(&self, id: &str) -> Result<(), DockerError> {
+        self.docker
+            .stop_container(id, None::<StopContainerOptions>)
+            .await?;
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<String, DockerError> {
+        let result = self.docker.prune_containers::<String>(None).await?;
+        let reclaimed = result.space_reclaimed.unwrap_or(0);
+        Ok(format!(
+            "Pruned unused containers, reclaimed {reclaimed} bytes"
+        ))
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), DockerError> {
+        let options = RemoveContainerOptions {
+            force: true,
+            ..Default::default()
+        };
+        self.docker.remove_container(id, Some(options)).await?;
+        Ok(())
+    }
+
+    async fn inspect(&self, id: &str) -> Result<String, DockerError> {
+        let info = self
+            .docker
+            .inspect_container(id, None::<InspectContainerOptions>)
+            .await?;
+        Ok(serde_json::to_string_pretty(&info).unwrap_or_else(|e| e.to_string()))
+    }
+
+    async fn top(&self, id: &str) -> Result<String, DockerError> {
+        let result = self.docker.top_processes(id, None::<TopOptions<String>>).await?;
+        let mut out = String::new();
+        if let Some(titles) = result.titles {
+            out.push_str(&titles.join("\t"));
+            out.push('\n');
+        }
+        for row in result.processes.unwrap_or_default() {
+            out.push_str(&row.join("\t"));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    async fn stats(&self, id: &str) -> Result<String, DockerError> {
+        let options = StatsOptions {
+            stream: false,
+            ..Default::default()
+        };
+        let mut stream = self.docker.stats(id, Some(options));
+        match stream.next().await {
+            Some(Ok(stats)) => {
+                let cpu_delta = stats.cpu_stats.cpu_usage.total_usage as f64
+                    - stats.precpu_stats.cpu_usage.total_usage as f64;
+                let system_delta = stats.cpu_stats.system_cpu_usage.unwrap_or(0) as f64
+                    - stats.precpu_stats.system_cpu_usage.unwrap_or(0) as f64;
+                let cpu_percent = if system_delta > 0.0 && cpu_delta > 0.0 {
+                    (cpu_delta / system_delta) * stats.cpu_stats.online_cpus.unwrap_or(1) as f64
+                        * 100.0
+                } else {
+                    0.0
+                };
+                let mem_usage = stats.memory_stats.usage.unwrap_or(0);
+                let mem_limit = stats.memory_stats.limit.unwrap_or(0);
+                Ok(format!(
+                    "CPU: {cpu_percent:.2}%  Mem: {mem_usage} / {mem_limit} bytes"
+                ))
+            }
+            Some(Err(e)) => Err(e.into()),
+            None => Ok("no stats available".to_string()),
+        }
+    }
+
+    fn follow_logs(&self, id: &str) -> mpsc::Receiver<Result<String, DockerError>> {
+        let (tx, rx) = mpsc::channel(1024);
+        let docker = self.docker.clone();
+        let id = id.to_string();
+        tokio::spawn(async move {
+            let options = LogsOptions::<String> {
+                follow: true,
+                stdout: true,
+                stderr: true,
+                tail: "100".to_string(),
+                ..Default::default()
+            };
+            let mut stream = docker.logs(&id, Some(options));
+            while let Some(chunk) = stream.next().await {
+                let line = chunk.map(|c| c.to_string()).map_err(DockerError::from);
+                if tx.send(line).await.is_err() {
+                    break;
+                }
+            }
+        });
+        rx
+    }
+
+    fn pull(&self, image: String) -> mpsc::Receiver<(String, Result<(), DockerError>)> {
+        let (tx, rx) = mpsc::channel(1);
+        let docker = self.docker.clone();
+        tokio::spawn(async move {
+            let options = CreateImageOptions {
+                from_image: image.as_str(),
+                ..Default::default()
+            };
+            let mut stream = docker.create_image(Some(options), None, None);
+            let mut result = Ok(());
+            while let Some(chunk) = stream.next().await {
+                if let Err(e) = chunk {
+                    result = Err(e.into());
+                    break;
+                }
+            }
+            drop(stream);
+            let _ = tx.send((image, result)).await;
+        });
+        rx
+    }
+
+    fn check_for_update(
+        &self,
+        image: String,
+        http: reqwest::Client,
+    ) -> mpsc::Receiver<(String, UpdateStatus)> {
+        let (tx, rx) = mpsc::channel(1);
+        let docker = self.docker.clone();
+        tokio::spawn(async move {
+            let local = docker
+                .inspect_image(&image)
+                .await
+                .ok()
+                .and_then(|info| {
+                    info.repo_digests.unwrap_or_default().into_iter().find_map(|repo_digest| {
+                        repo_digest.split_once('@').map(|(_, digest)| digest.to_string())
+                    })
+                });
+            let remote = registry::registry_digest(&http, &image).await;
+            let status = match (local, remote) {
+                (Some(local), Some(remote)) if registry::digests_match(&local, &remote) => {
+                    UpdateStatus::UpToDate
+                }
+                (Some(_), Some(_)) => UpdateStatus::UpdateAvailable,
+                _ => UpdateStatus::Unknown,
+            };
+            let _ = tx.send((image, status)).await;
+        });
+        rx
+    }
+}
+
+fn container_from_summary(summary: bollard::models::ContainerSummary) -> Container {
+    let ports = summary
+        .ports
+        .unwrap_or_default()
+        .iter()
+        .map(|p| match (p.public_port, p.ip.as_ref()) {
+            (Some(public), Some(ip)) => format!("{ip}:{public}->{}", p.private_port),
+            _ => p.private_port.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let names = summary
+        .names
+        .unwrap_or_default()
+        .into_iter()
+        .map(|n| n.trim_start_matches('/').to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Container {
+        id: summary.id.unwrap_or_default(),
+        image: summary.image.unwrap_or_default(),
+        command: summary.command.unwrap_or_default(),
+        created_at: summary
+            .created
+            .map(|secs| secs.to_string())
+            .unwrap_or_default(),
+        status: summary.status.unwrap_or_default(),
+        ports,
+        names,
+    }
+}
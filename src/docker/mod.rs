@@ -0,0 +1,83 @@
+//! Docker transport abstraction.
+//!
+//! `run_app` talks to a `dyn DockerClient` rather than shelling out to the
+//! `docker` CLI, so the Engine API (via `bollard`) can sit behind the same
+//! interface a CLI-backed implementation would.
+
+mod bollard_client;
+
+pub use bollard_client::BollardClient;
+
+use crate::registry::UpdateStatus;
+use std::fmt;
+use tokio::sync::mpsc;
+
+/// A container as shown in the table, independent of how it was fetched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Container {
+    pub id: String,
+    pub image: String,
+    pub command: String,
+    pub created_at: String,
+    pub status: String,
+    pub ports: String,
+    pub names: String,
+}
+
+#[derive(Debug)]
+pub enum DockerError {
+    Connection(String),
+    Api(String),
+}
+
+impl fmt::Display for DockerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DockerError::Connection(msg) => write!(f, "failed to connect to Docker: {msg}"),
+            DockerError::Api(msg) => write!(f, "Docker API error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DockerError {}
+
+impl From<bollard::errors::Error> for DockerError {
+    fn from(err: bollard::errors::Error) -> Self {
+        DockerError::Api(err.to_string())
+    }
+}
+
+/// Everything `run_app` needs from Docker, regardless of transport.
+#[async_trait::async_trait]
+pub trait DockerClient: Send + Sync {
+    async fn list_containers(&self, all: bool) -> Result<Vec<Container>, DockerError>;
+    async fn stop(&self, id: &str) -> Result<(), DockerError>;
+    /// Removes all stopped containers and returns a summary line. Unused
+    /// images, networks, volumes and build cache are not pruned.
+    async fn prune(&self) -> Result<String, DockerError>;
+    async fn remove(&self, id: &str) -> Result<(), DockerError>;
+
+    /// Pretty-printed `docker inspect` style JSON config.
+    async fn inspect(&self, id: &str) -> Result<String, DockerError>;
+    /// The running process table, formatted as tab-separated rows.
+    async fn top(&self, id: &str) -> Result<String, DockerError>;
+    /// A single CPU/memory sample, formatted for display.
+    async fn stats(&self, id: &str) -> Result<String, DockerError>;
+
+    /// Starts `docker logs -f` in the background and streams lines back on
+    /// the returned channel until the container stops or the receiver is
+    /// dropped.
+    fn follow_logs(&self, id: &str) -> mpsc::Receiver<Result<String, DockerError>>;
+
+    /// Pulls the latest version of `image` in the background (equivalent to
+    /// `docker pull`), mirroring `follow_logs`'s pattern so a slow pull
+    /// doesn't block the render loop; the result arrives once on the
+    /// returned channel.
+    fn pull(&self, image: String) -> mpsc::Receiver<(String, Result<(), DockerError>)>;
+
+    /// Checks, in the background, whether Docker Hub's digest for `image`'s
+    /// tag differs from the local image's digest. Mirrors `follow_logs`'s pattern so neither
+    /// the local digest lookup nor a slow/unreachable registry ever blocks
+    /// the render loop; the result arrives once on the returned channel.
+    fn check_for_update(&self, image: String, http: reqwest::Client) -> mpsc::Receiver<(String, UpdateStatus)>;
+}
@@ -0,0 +1,338 @@
+//! Configurable keybindings.
+//!
+//! Keys are no longer hardcoded in the event loop: each logical [`Action`]
+//! is bound to one or more key chords (e.g. `ctrl+c`, or a sequence like
+//! `g g`), loaded from a TOML config file with built-in defaults as a
+//! fallback when no file exists or an action is left unbound.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NavUp,
+    NavDown,
+    NextCommand,
+    PrevCommand,
+    Activate,
+    StopContainer,
+    Confirm,
+    Cancel,
+    PageUp,
+    PageDown,
+    ToggleFollow,
+    Refresh,
+    PullImage,
+}
+
+/// A single key press: a code plus modifiers (`ctrl+c`, `g`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    fn parse(token: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts = token.split('+').peekable();
+        let mut last = "";
+        while let Some(part) = parts.next() {
+            if parts.peek().is_some() {
+                match part.to_ascii_lowercase().as_str() {
+                    "ctrl" => modifiers |= KeyModifiers::CONTROL,
+                    "shift" => modifiers |= KeyModifiers::SHIFT,
+                    "alt" => modifiers |= KeyModifiers::ALT,
+                    _ => return None,
+                }
+            } else {
+                last = part;
+            }
+        }
+        let code = match last.to_ascii_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "tab" => KeyCode::Tab,
+            single if single.chars().count() == 1 => {
+                KeyCode::Char(single.chars().next().unwrap())
+            }
+            _ => return None,
+        };
+        Some(KeyChord { code, modifiers })
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code && self.modifiers == key.modifiers
+    }
+}
+
+/// A chord sequence bound to an action, e.g. `["g", "g"]`.
+#[derive(Debug, Clone)]
+struct Binding(Vec<KeyChord>);
+
+impl Binding {
+    fn parse(spec: &str) -> Option<Self> {
+        let chords = spec
+            .split_whitespace()
+            .map(KeyChord::parse)
+            .collect::<Option<Vec<_>>>()?;
+        if chords.is_empty() {
+            None
+        } else {
+            Some(Binding(chords))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct KeymapFile {
+    #[serde(default)]
+    keys: HashMap<String, String>,
+}
+
+/// Lookup table the event loop consults instead of matching `KeyCode`s
+/// directly, plus the in-progress chord buffer for multi-key sequences.
+pub struct Keymap {
+    bindings: Vec<(Action, Binding)>,
+    pending: Vec<KeyEvent>,
+}
+
+impl Keymap {
+    /// Loads `path` if it exists, falling back to built-in defaults for
+    /// any action missing from the file (or if the file itself is absent).
+    pub fn load(path: &Path) -> Self {
+        let mut bindings = default_bindings();
+
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if let Ok(file) = toml::from_str::<KeymapFile>(&contents) {
+                for (name, spec) in file.keys {
+                    let (Some(action), Some(binding)) =
+                        (action_from_name(&name), Binding::parse(&spec))
+                    else {
+                        continue;
+                    };
+                    bindings.retain(|(a, _)| *a != action);
+                    bindings.push((action, binding));
+                }
+            }
+        }
+
+        Keymap {
+            bindings,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Default config location: `./keymap.toml`.
+    pub fn default_path() -> PathBuf {
+        PathBuf::from("keymap.toml")
+    }
+
+    /// Feeds one key event through the chord buffer, returning the action
+    /// it completes (if any). Keys that don't extend a pending chord reset
+    /// the buffer and are retried as the start of a new one.
+    pub fn resolve(&mut self, key: KeyEvent) -> Option<Action> {
+        self.pending.push(key);
+
+        if let Some(action) = self.match_exact() {
+            self.pending.clear();
+            return Some(action);
+        }
+
+        if self.has_prefix_match() {
+            return None;
+        }
+
+        self.pending.clear();
+        self.pending.push(key);
+        if let Some(action) = self.match_exact() {
+            self.pending.clear();
+            return Some(action);
+        }
+        if !self.has_prefix_match() {
+            self.pending.clear();
+        }
+        None
+    }
+
+    fn match_exact(&self) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, binding)| {
+                binding.0.len() == self.pending.len()
+                    && binding.0.iter().zip(&self.pending).all(|(c, k)| c.matches(k))
+            })
+            .map(|(action, _)| *action)
+    }
+
+    fn has_prefix_match(&self) -> bool {
+        self.bindings.iter().any(|(_, binding)| {
+            binding.0.len() > self.pending.len()
+                && binding.0.iter().zip(&self.pending).all(|(c, k)| c.matches(k))
+        })
+    }
+}
+
+fn action_from_name(name: &str) -> Option<Action> {
+    Some(match name {
+        "quit" => Action::Quit,
+        "nav_up" => Action::NavUp,
+        "nav_down" => Action::NavDown,
+        "next_command" => Action::NextCommand,
+        "prev_command" => Action::PrevCommand,
+        "activate" => Action::Activate,
+        "stop_container" => Action::StopContainer,
+        "confirm" => Action::Confirm,
+        "cancel" => Action::Cancel,
+        "page_up" => Action::PageUp,
+        "page_down" => Action::PageDown,
+        "toggle_follow" => Action::ToggleFollow,
+        "refresh" => Action::Refresh,
+        "pull_image" => Action::PullImage,
+        _ => return None,
+    })
+}
+
+fn default_bindings() -> Vec<(Action, Binding)> {
+    let defaults: &[(Action, &str)] = &[
+        (Action::Quit, "q"),
+        (Action::NavUp, "up"),
+        (Action::NavDown, "down"),
+        (Action::NextCommand, "right"),
+        (Action::PrevCommand, "left"),
+        (Action::Activate, "enter"),
+        (Action::StopContainer, "s"),
+        (Action::Confirm, "y"),
+        (Action::Cancel, "n"),
+        (Action::PageUp, "pageup"),
+        (Action::PageDown, "pagedown"),
+        (Action::ToggleFollow, "f"),
+        (Action::Refresh, "r"),
+        (Action::PullImage, "u"),
+    ];
+    defaults
+        .iter()
+        .map(|(action, spec)| (*action, Binding::parse(spec).expect("valid default binding")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn parses_plain_char() {
+        let chord = KeyChord::parse("g").unwrap();
+        assert_eq!(chord.code, KeyCode::Char('g'));
+        assert_eq!(chord.modifiers, KeyModifiers::NONE);
+    }
+
+    #[test]
+    fn parses_named_key() {
+        let chord = KeyChord::parse("pageup").unwrap();
+        assert_eq!(chord.code, KeyCode::PageUp);
+    }
+
+    #[test]
+    fn parses_single_modifier() {
+        let chord = KeyChord::parse("ctrl+c").unwrap();
+        assert_eq!(chord.code, KeyCode::Char('c'));
+        assert_eq!(chord.modifiers, KeyModifiers::CONTROL);
+    }
+
+    #[test]
+    fn parses_stacked_modifiers() {
+        let chord = KeyChord::parse("ctrl+shift+up").unwrap();
+        assert_eq!(chord.code, KeyCode::Up);
+        assert_eq!(chord.modifiers, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+    }
+
+    #[test]
+    fn rejects_unknown_modifier() {
+        assert!(KeyChord::parse("meta+c").is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_key_name() {
+        assert!(KeyChord::parse("frobnicate").is_none());
+    }
+
+    #[test]
+    fn binding_rejects_empty_spec() {
+        assert!(Binding::parse("").is_none());
+        assert!(Binding::parse("   ").is_none());
+    }
+
+    #[test]
+    fn binding_parses_multi_chord_sequence() {
+        let binding = Binding::parse("g g").unwrap();
+        assert_eq!(binding.0.len(), 2);
+    }
+
+    #[test]
+    fn resolve_matches_single_chord_binding() {
+        let mut keymap = Keymap {
+            bindings: vec![(Action::Quit, Binding::parse("q").unwrap())],
+            pending: Vec::new(),
+        };
+        assert_eq!(
+            keymap.resolve(key(KeyCode::Char('q'), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+    }
+
+    #[test]
+    fn resolve_matches_multi_chord_sequence() {
+        let mut keymap = Keymap {
+            bindings: vec![(Action::Refresh, Binding::parse("g g").unwrap())],
+            pending: Vec::new(),
+        };
+        assert_eq!(keymap.resolve(key(KeyCode::Char('g'), KeyModifiers::NONE)), None);
+        assert_eq!(
+            keymap.resolve(key(KeyCode::Char('g'), KeyModifiers::NONE)),
+            Some(Action::Refresh)
+        );
+    }
+
+    #[test]
+    fn resolve_restarts_sequence_after_non_prefix_key() {
+        let mut keymap = Keymap {
+            bindings: vec![(Action::Refresh, Binding::parse("g g").unwrap())],
+            pending: Vec::new(),
+        };
+        assert_eq!(keymap.resolve(key(KeyCode::Char('g'), KeyModifiers::NONE)), None);
+        // A key that doesn't extend "g" and isn't itself bound clears the buffer.
+        assert_eq!(keymap.resolve(key(KeyCode::Char('x'), KeyModifiers::NONE)), None);
+        assert!(keymap.pending.is_empty());
+    }
+
+    #[test]
+    fn resolve_prefers_exact_match_over_a_longer_ambiguous_sequence() {
+        // "g" alone is bound, and "g g" is also bound: an exact match fires
+        // immediately rather than waiting to see if a second chord follows.
+        let mut keymap = Keymap {
+            bindings: vec![
+                (Action::Quit, Binding::parse("g").unwrap()),
+                (Action::Refresh, Binding::parse("g g").unwrap()),
+            ],
+            pending: Vec::new(),
+        };
+        assert_eq!(
+            keymap.resolve(key(KeyCode::Char('g'), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+    }
+}
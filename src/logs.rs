@@ -0,0 +1,148 @@
+//! Bounded ring buffer for a single container's followed log output.
+//!
+//! Lines arrive from a background streaming task (see
+//! `docker::DockerClient::follow_logs`) and are parsed from raw ANSI bytes
+//! into styled `ratatui` spans so colored log output renders instead of
+//! printing escape sequences literally.
+
+use ansi_to_tui::IntoText;
+use ratatui::text::Line;
+use std::collections::VecDeque;
+
+/// Cap on retained lines; oldest lines are dropped once exceeded.
+pub const MAX_LINES: usize = 10_000;
+
+/// Default number of lines a PageUp/PageDown jumps.
+pub const PAGE_SIZE: usize = 10;
+
+pub struct LogBuffer {
+    lines: VecDeque<Line<'static>>,
+    /// Index of the topmost visible line when not following.
+    pub scroll: usize,
+    /// When true, the view sticks to the most recent line.
+    pub follow: bool,
+}
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        Self {
+            lines: VecDeque::with_capacity(MAX_LINES),
+            scroll: 0,
+            follow: true,
+        }
+    }
+
+    pub fn push(&mut self, raw: &str) {
+        if self.lines.len() >= MAX_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(parse_ansi_line(raw));
+        if self.follow {
+            self.scroll = self.lines.len().saturating_sub(1);
+        }
+    }
+
+    pub fn lines(&self) -> &VecDeque<Line<'static>> {
+        &self.lines
+    }
+
+    pub fn page_up(&mut self) {
+        self.follow = false;
+        self.scroll = self.scroll.saturating_sub(PAGE_SIZE);
+    }
+
+    pub fn page_down(&mut self) {
+        let max = self.lines.len().saturating_sub(1);
+        self.scroll = (self.scroll + PAGE_SIZE).min(max);
+        if self.scroll >= max {
+            self.follow = true;
+        }
+    }
+
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+        if self.follow {
+            self.scroll = self.lines.len().saturating_sub(1);
+        }
+    }
+}
+
+fn parse_ansi_line(raw: &str) -> Line<'static> {
+    raw.as_bytes()
+        .into_text()
+        .ok()
+        .and_then(|text| text.lines.into_iter().next())
+        .unwrap_or_else(|| Line::from(raw.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_scrolls_to_latest_while_following() {
+        let mut buf = LogBuffer::new();
+        buf.push("one");
+        buf.push("two");
+        buf.push("three");
+        assert_eq!(buf.scroll, 2);
+        assert!(buf.follow);
+    }
+
+    #[test]
+    fn push_drops_oldest_line_past_max() {
+        let mut buf = LogBuffer::new();
+        for i in 0..MAX_LINES + 1 {
+            buf.push(&i.to_string());
+        }
+        assert_eq!(buf.lines().len(), MAX_LINES);
+        assert_eq!(buf.lines().front().unwrap().to_string(), "1");
+    }
+
+    #[test]
+    fn page_up_stops_following_and_moves_back_a_page() {
+        let mut buf = LogBuffer::new();
+        for i in 0..50 {
+            buf.push(&i.to_string());
+        }
+        buf.page_up();
+        assert!(!buf.follow);
+        assert_eq!(buf.scroll, 49 - PAGE_SIZE);
+    }
+
+    #[test]
+    fn page_up_saturates_at_zero() {
+        let mut buf = LogBuffer::new();
+        buf.push("one");
+        buf.page_up();
+        assert_eq!(buf.scroll, 0);
+    }
+
+    #[test]
+    fn page_down_resumes_following_once_it_reaches_the_end() {
+        let mut buf = LogBuffer::new();
+        for i in 0..50 {
+            buf.push(&i.to_string());
+        }
+        buf.page_up();
+        buf.page_up();
+        assert!(!buf.follow);
+        for _ in 0..10 {
+            buf.page_down();
+        }
+        assert!(buf.follow);
+        assert_eq!(buf.scroll, 49);
+    }
+
+    #[test]
+    fn toggle_follow_jumps_to_latest_line_when_enabled() {
+        let mut buf = LogBuffer::new();
+        buf.push("one");
+        buf.push("two");
+        buf.page_up();
+        assert!(!buf.follow);
+        buf.toggle_follow();
+        assert!(buf.follow);
+        assert_eq!(buf.scroll, 1);
+    }
+}
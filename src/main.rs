@@ -1,55 +1,118 @@
-use async_process::Command;
-use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode};
+mod docker;
+mod keymap;
+mod logs;
+mod registry;
+
+use clap::Parser;
+use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyEvent};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
+use docker::{BollardClient, Container, DockerClient, DockerError};
+use keymap::{Action, Keymap};
+use logs::LogBuffer;
+use registry::{RegistryChecker, UpdateStatus};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table},
-    Terminal,
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Row, Table},
+    Frame, Terminal,
 };
-use serde::Deserialize;
+use std::io::Write;
 use std::{error::Error, io, time::Duration};
 use tokio::{sync::mpsc, task};
 
-#[derive(Debug, Deserialize)]
-struct ContainerInfo {
-    #[serde(rename = "ID")]
-    id: String,
-    #[serde(rename = "Image")]
-    image: String,
-    #[serde(rename = "Command")]
-    command: String,
-    #[serde(rename = "CreatedAt")]
-    created_at: String,
-    #[serde(rename = "Status")]
-    status: String,
-    #[serde(rename = "Ports")]
-    ports: String,
-    #[serde(rename = "Names")]
-    names: String,
+/// A simple Docker TUI.
+#[derive(Parser)]
+#[command(author, version, about)]
+struct Cli {
+    /// How often to refresh the container list, in milliseconds.
+    #[arg(long, default_value_t = 1000)]
+    tick_rate: u64,
+}
+
+/// RAII wrapper that always hands the terminal back to the shell, even if
+/// `run_app` panics or returns early: raw mode, the alternate screen, and
+/// mouse capture are undone on drop rather than only on the happy path.
+struct TerminalGuard {
+    terminal: Terminal<CrosstermBackend<io::Stdout>>,
+}
+
+impl TerminalGuard {
+    fn new() -> Result<Self, Box<dyn Error>> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+        Ok(Self { terminal })
+    }
+}
+
+impl std::ops::Deref for TerminalGuard {
+    type Target = Terminal<CrosstermBackend<io::Stdout>>;
+    fn deref(&self) -> &Self::Target {
+        &self.terminal
+    }
+}
+
+impl std::ops::DerefMut for TerminalGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.terminal
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+/// Leaves raw mode / the alternate screen / mouse capture, ignoring errors
+/// since this also runs from the panic hook where we can't do much about a
+/// failed restore anyway.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        crossterm::cursor::Show
+    );
+    let _ = io::stdout().flush();
+}
+
+/// Installs a panic hook that restores the terminal *before* the default
+/// hook prints the panic message, so the message lands on a normal screen
+/// instead of a corrupted alternate-screen/raw-mode one.
+fn install_panic_hook() {
+    let original = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        original(info);
+    }));
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let cli = Cli::parse();
+    let client = BollardClient::connect()?;
+    let keymap = Keymap::load(&Keymap::default_path());
 
-    let res = run_app(&mut terminal).await;
+    install_panic_hook();
+    let mut guard = TerminalGuard::new()?;
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    let res = run_app(
+        &mut guard,
+        &client,
+        keymap,
+        Duration::from_millis(cli.tick_rate),
+    )
+    .await;
+
+    drop(guard);
 
     if let Err(err) = res {
         println!("{:?}", err)
@@ -58,208 +121,558 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-async fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> Result<(), Box<dyn Error>> {
+/// What the middle pane currently shows, in place of the container table.
+enum DetailView {
+    Logs(LogBuffer),
+    Inspect(String),
+    Top(String),
+    Stats(String),
+}
+
+/// A destructive action awaiting `y`/`n` confirmation.
+enum PendingAction {
+    Stop(String),
+    Remove(String),
+    Prune,
+}
+
+impl PendingAction {
+    fn prompt(&self) -> String {
+        match self {
+            PendingAction::Stop(id) => format!("Stop container {id}? [y/N]"),
+            PendingAction::Remove(id) => format!("Remove container {id}? [y/N]"),
+            PendingAction::Prune => "Prune all stopped containers? [y/N]".to_string(),
+        }
+    }
+}
+
+async fn dispatch_pending(client: &dyn DockerClient, action: PendingAction) -> String {
+    match action {
+        PendingAction::Stop(id) => match client.stop(&id).await {
+            Ok(()) => format!("Stopped container {id}"),
+            Err(e) => format!("Failed to stop container: {e}"),
+        },
+        PendingAction::Remove(id) => match client.remove(&id).await {
+            Ok(()) => format!("Removed container {id}"),
+            Err(e) => format!("Failed to remove container: {e}"),
+        },
+        PendingAction::Prune => match client.prune().await {
+            Ok(msg) => msg,
+            Err(e) => format!("Failed to prune containers: {e}"),
+        },
+    }
+}
+
+/// A `Rect` centered within `r`, `percent_x`/`percent_y` of its size.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+/// Events driving the render loop: user input, a periodic refresh tick, a
+/// line from a followed log stream, the result of a registry update check
+/// or of an image pull, a terminal resize, or a shutdown signal from the OS.
+enum AppEvent {
+    Input(KeyEvent),
+    Tick,
+    Log(Result<String, DockerError>),
+    RegistryResult(String, UpdateStatus),
+    PullResult(String, Result<(), DockerError>),
+    Resize,
+    Shutdown,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_ui(
+    f: &mut Frame,
+    commands: &[&str],
+    selected_command: usize,
+    containers: &[Container],
+    selected_container: usize,
+    detail: &Option<DetailView>,
+    status_message: &str,
+    pending_confirm: &Option<PendingAction>,
+    registry: &RegistryChecker,
+) {
+    let size = f.area();
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(20),
+                Constraint::Percentage(75),
+                Constraint::Percentage(5),
+            ]
+            .as_ref(),
+        )
+        .split(size);
+
+    let menu_items: Vec<ListItem> = commands
+        .iter()
+        .enumerate()
+        .map(|(i, cmd)| {
+            let style = if i == selected_command {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            ListItem::new(cmd.to_string()).style(style)
+        })
+        .collect();
+    let menu =
+        List::new(menu_items).block(Block::default().borders(Borders::ALL).title("Commands"));
+    f.render_widget(menu, chunks[0]);
+
+    if let Some(detail) = detail {
+        match detail {
+            DetailView::Logs(buf) => {
+                let height = chunks[1].height.saturating_sub(2) as usize;
+                let total = buf.lines().len();
+                let top = if buf.follow {
+                    total.saturating_sub(height)
+                } else {
+                    buf.scroll.min(total.saturating_sub(height))
+                };
+                let lines: Vec<Line> = buf.lines().iter().cloned().collect();
+                let title = if buf.follow { "Logs [follow]" } else { "Logs" };
+                let view = Paragraph::new(lines)
+                    .scroll((top as u16, 0))
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                f.render_widget(view, chunks[1]);
+            }
+            DetailView::Inspect(text) | DetailView::Top(text) | DetailView::Stats(text) => {
+                let title = match detail {
+                    DetailView::Inspect(_) => "Inspect",
+                    DetailView::Top(_) => "Top",
+                    DetailView::Stats(_) => "Stats",
+                    DetailView::Logs(_) => unreachable!(),
+                };
+                let view = Paragraph::new(text.clone())
+                    .block(Block::default().borders(Borders::ALL).title(title));
+                f.render_widget(view, chunks[1]);
+            }
+        }
+    } else {
+        let rows = containers.iter().enumerate().map(|(i, c)| {
+            let outdated = registry.status(&c.image) == UpdateStatus::UpdateAvailable;
+            let mut style = if i == selected_container {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            if outdated && i != selected_container {
+                style = style.fg(Color::Magenta);
+            }
+            let image = if outdated {
+                format!("{} \u{2191}", c.image)
+            } else {
+                c.image.clone()
+            };
+            Row::new(vec![
+                c.id.clone(),
+                image,
+                c.command.clone(),
+                c.status.clone(),
+                c.names.clone(),
+            ])
+            .style(style)
+        });
+        let table = Table::new(
+            rows,
+            &[
+                Constraint::Length(12),
+                Constraint::Length(20),
+                Constraint::Length(30),
+                Constraint::Length(20),
+                Constraint::Length(20),
+            ],
+        )
+        .header(
+            Row::new(vec!["ID", "Image", "Command", "Status", "Names"]).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Docker Containers"),
+        );
+        f.render_widget(table, chunks[1]);
+    }
+
+    let status = Paragraph::new(status_message.to_string())
+        .style(Style::default().fg(Color::White).bg(Color::Blue));
+    f.render_widget(status, chunks[2]);
+
+    if let Some(action) = pending_confirm {
+        let popup = centered_rect(40, 15, size);
+        let modal = Paragraph::new(action.prompt()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm")
+                .style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(Clear, popup);
+        f.render_widget(modal, popup);
+    }
+}
+
+async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    client: &dyn DockerClient,
+    mut keymap: Keymap,
+    tick_rate: Duration,
+) -> Result<(), Box<dyn Error>>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
     let (tx, mut rx) = mpsc::channel(100);
+
+    let input_tx = tx.clone();
     task::spawn(async move {
         loop {
             if event::poll(Duration::from_millis(100)).unwrap() {
-                if let CEvent::Key(key) = event::read().unwrap() {
-                    tx.send(key).await.unwrap();
+                let sent = match event::read().unwrap() {
+                    CEvent::Key(key) => input_tx.send(AppEvent::Input(key)).await,
+                    CEvent::Resize(_, _) => input_tx.send(AppEvent::Resize).await,
+                    _ => Ok(()),
+                };
+                if sent.is_err() {
+                    break;
                 }
             }
         }
     });
 
-    let commands = vec!["ps", "ps -a", "stop", "prune"];
+    let tick_tx = tx.clone();
+    task::spawn(async move {
+        let mut interval = tokio::time::interval(tick_rate);
+        loop {
+            interval.tick().await;
+            if tick_tx.send(AppEvent::Tick).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let shutdown_tx = tx.clone();
+    task::spawn(async move {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+            let mut sigterm =
+                signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+        let _ = shutdown_tx.send(AppEvent::Shutdown).await;
+    });
+
+    let commands = vec![
+        "ps", "ps -a", "stop", "rm", "prune", "logs", "inspect", "top", "stats",
+    ];
     let mut selected_command = 0;
     let mut selected_container = 0;
-    let mut containers = Vec::new();
+    let mut containers: Vec<Container> = Vec::new();
     let mut all_flag = false;
     let mut status_message = String::new();
+    let mut detail: Option<DetailView> = None;
+    let mut log_task: Option<task::JoinHandle<()>> = None;
+    let mut pending_confirm: Option<PendingAction> = None;
+    let mut registry = RegistryChecker::new();
+    let http = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    terminal.draw(|f| {
+        draw_ui(
+            f,
+            &commands,
+            selected_command,
+            &containers,
+            selected_container,
+            &detail,
+            &status_message,
+            &pending_confirm,
+            &registry,
+        )
+    })?;
+    let mut dirty = false;
 
-    loop {
-        if commands[selected_command] == "ps" || commands[selected_command] == "ps -a" {
-            containers = get_docker_ps_output(all_flag).await;
-        }
+    while let Some(event) = rx.recv().await {
+        match event {
+            AppEvent::Shutdown => break,
+            AppEvent::Resize => {
+                dirty = true;
+            }
+            AppEvent::Tick => {
+                if commands[selected_command] == "ps" || commands[selected_command] == "ps -a" {
+                    match client.list_containers(all_flag).await {
+                        Ok(new_containers) => {
+                            if new_containers != containers {
+                                containers = new_containers;
+                                dirty = true;
+                            }
+                        }
+                        Err(e) => {
+                            status_message = format!("Failed to list containers: {e}");
+                            dirty = true;
+                        }
+                    }
+                }
 
-        terminal.draw(|f| {
-            let size = f.area();
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(
-                    [
-                        Constraint::Percentage(20),
-                        Constraint::Percentage(75),
-                        Constraint::Percentage(5),
-                    ]
-                    .as_ref(),
-                )
-                .split(size);
-
-            let menu_items: Vec<ListItem> = commands
-                .iter()
-                .enumerate()
-                .map(|(i, cmd)| {
-                    let style = if i == selected_command {
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD)
-                    } else {
-                        Style::default()
-                    };
-                    ListItem::new(cmd.to_string()).style(style)
-                })
-                .collect();
-            let menu = List::new(menu_items)
-                .block(Block::default().borders(Borders::ALL).title("Commands"));
-            f.render_widget(menu, chunks[0]);
-
-            let rows = containers.iter().enumerate().map(|(i, c)| {
-                let style = if i == selected_container {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default()
+                if let Some(image) = registry
+                    .stale_image(containers.iter().map(|c| c.image.as_str()))
+                    .map(|image| image.to_string())
+                {
+                    // Recorded now so this image isn't re-queued every tick
+                    // while the background check is still in flight.
+                    registry.record(image.clone(), UpdateStatus::Unknown);
+                    let mut result_rx = client.check_for_update(image, http.clone());
+                    let forward_tx = tx.clone();
+                    task::spawn(async move {
+                        if let Some((image, status)) = result_rx.recv().await {
+                            let _ = forward_tx
+                                .send(AppEvent::RegistryResult(image, status))
+                                .await;
+                        }
+                    });
+                }
+            }
+            AppEvent::RegistryResult(image, status) => {
+                registry.record(image, status);
+                dirty = true;
+            }
+            AppEvent::PullResult(image, result) => {
+                status_message = match result {
+                    Ok(()) => format!("Pulled {image}"),
+                    Err(e) => format!("Failed to pull image: {e}"),
                 };
-                Row::new(vec![
-                    c.id.clone(),
-                    c.image.clone(),
-                    c.command.clone(),
-                    c.status.clone(),
-                    c.names.clone(),
-                ])
-                .style(style)
-            });
-            let table = Table::new(
-                rows,
-                &[
-                    Constraint::Length(12),
-                    Constraint::Length(20),
-                    Constraint::Length(30),
-                    Constraint::Length(20),
-                    Constraint::Length(20),
-                ],
-            )
-            .header(
-                Row::new(vec!["ID", "Image", "Command", "Status", "Names"]).style(
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-            )
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Docker Containers"),
-            );
-            f.render_widget(table, chunks[1]);
-
-            let status = Paragraph::new(status_message.clone())
-                .style(Style::default().fg(Color::White).bg(Color::Blue));
-            f.render_widget(status, chunks[2]);
-        })?;
-
-        if let Ok(key) = rx.try_recv() {
-            match key.code {
-                KeyCode::Char('q') => break,
-                KeyCode::Down => {
-                    if selected_container < containers.len().saturating_sub(1) {
-                        selected_container += 1;
+                dirty = true;
+            }
+            AppEvent::Log(line) => {
+                if let Some(DetailView::Logs(buf)) = detail.as_mut() {
+                    match line {
+                        Ok(text) => buf.push(&text),
+                        Err(e) => buf.push(&format!("[error: {e}]")),
                     }
+                    dirty = true;
                 }
-                KeyCode::Up => {
-                    if selected_container > 0 {
+            }
+            AppEvent::Input(key) => {
+                let Some(action) = keymap.resolve(key) else {
+                    continue;
+                };
+
+                if let Some(pending) = pending_confirm.take() {
+                    status_message = match action {
+                        Action::Confirm => dispatch_pending(client, pending).await,
+                        _ => {
+                            pending_confirm = None;
+                            "Cancelled".to_string()
+                        }
+                    };
+                    dirty = true;
+                    continue;
+                }
+
+                match action {
+                    Action::Quit => break,
+                    Action::NavDown if selected_container < containers.len().saturating_sub(1) => {
+                        selected_container += 1;
+                    }
+                    Action::NavDown => {}
+                    Action::NavUp if selected_container > 0 => {
                         selected_container -= 1;
                     }
-                }
-                KeyCode::Right => {
-                    selected_command = (selected_command + 1) % commands.len();
-                }
-                KeyCode::Left => {
-                    if selected_command == 0 {
-                        selected_command = commands.len() - 1;
-                    } else {
-                        selected_command -= 1;
+                    Action::NavUp => {}
+                    Action::NextCommand => {
+                        selected_command = (selected_command + 1) % commands.len();
                     }
-                }
-                KeyCode::Char('s') => {
-                    if let Some(container) = containers.get(selected_container) {
-                        let container_id = &container.id;
-                        let output = Command::new("docker")
-                            .arg("stop")
-                            .arg(container_id)
-                            .output()
-                            .await;
-                        match output {
-                            Ok(_) => status_message = format!("Stopped container {}", container_id),
-                            Err(e) => status_message = format!("Failed to stop container: {}", e),
+                    Action::PrevCommand => {
+                        if selected_command == 0 {
+                            selected_command = commands.len() - 1;
+                        } else {
+                            selected_command -= 1;
                         }
                     }
-                }
-                KeyCode::Enter => match commands[selected_command] {
-                    "stop" => {
-                        if let Some(container) = containers.get(selected_container) {
-                            let container_id = &container.id;
-                            let output = Command::new("docker")
-                                .arg("stop")
-                                .arg(container_id)
-                                .output()
-                                .await;
-                            match output {
-                                Ok(_) => {
-                                    status_message = format!("Stopped container {}", container_id)
-                                }
-                                Err(e) => {
-                                    status_message = format!("Failed to stop container: {}", e)
-                                }
-                            }
+                    Action::PageUp => {
+                        if let Some(DetailView::Logs(buf)) = detail.as_mut() {
+                            buf.page_up();
                         }
                     }
-                    "prune" => {
-                        let output = Command::new("docker")
-                            .arg("system")
-                            .arg("prune")
-                            .arg("-f")
-                            .output()
-                            .await;
-                        match output {
-                            Ok(_) => status_message = "System pruned".to_string(),
-                            Err(e) => status_message = format!("Failed to prune system: {}", e),
+                    Action::PageDown => {
+                        if let Some(DetailView::Logs(buf)) = detail.as_mut() {
+                            buf.page_down();
                         }
                     }
-                    "ps" => {
-                        all_flag = false;
-                        selected_container = 0;
+                    Action::ToggleFollow => {
+                        if let Some(DetailView::Logs(buf)) = detail.as_mut() {
+                            buf.toggle_follow();
+                        }
                     }
-                    "ps -a" => {
-                        all_flag = true;
-                        selected_container = 0;
+                    Action::Refresh
+                        if commands[selected_command] == "ps"
+                            || commands[selected_command] == "ps -a" =>
+                    {
+                        containers = match client.list_containers(all_flag).await {
+                            Ok(containers) => containers,
+                            Err(e) => {
+                                status_message = format!("Failed to list containers: {e}");
+                                Vec::new()
+                            }
+                        };
                     }
+                    Action::Refresh => {}
+                    Action::StopContainer => {
+                        if let Some(container) = containers.get(selected_container) {
+                            pending_confirm = Some(PendingAction::Stop(container.id.clone()));
+                        }
+                    }
+                    Action::PullImage => {
+                        if let Some(container) = containers.get(selected_container) {
+                            status_message = format!("Pulling {}...", container.image);
+                            let mut pull_rx = client.pull(container.image.clone());
+                            let forward_tx = tx.clone();
+                            task::spawn(async move {
+                                if let Some((image, result)) = pull_rx.recv().await {
+                                    let _ = forward_tx.send(AppEvent::PullResult(image, result)).await;
+                                }
+                            });
+                        }
+                    }
+                    Action::Activate => match commands[selected_command] {
+                        "stop" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                pending_confirm = Some(PendingAction::Stop(container.id.clone()));
+                            }
+                        }
+                        "rm" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                pending_confirm =
+                                    Some(PendingAction::Remove(container.id.clone()));
+                            }
+                        }
+                        "prune" => {
+                            pending_confirm = Some(PendingAction::Prune);
+                        }
+                        "ps" => {
+                            all_flag = false;
+                            selected_container = 0;
+                            detail = None;
+                            if let Some(handle) = log_task.take() {
+                                handle.abort();
+                            }
+                        }
+                        "ps -a" => {
+                            all_flag = true;
+                            selected_container = 0;
+                            detail = None;
+                            if let Some(handle) = log_task.take() {
+                                handle.abort();
+                            }
+                        }
+                        "logs" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                if let Some(handle) = log_task.take() {
+                                    handle.abort();
+                                }
+                                let mut log_rx = client.follow_logs(&container.id);
+                                let forward_tx = tx.clone();
+                                log_task = Some(task::spawn(async move {
+                                    while let Some(line) = log_rx.recv().await {
+                                        if forward_tx.send(AppEvent::Log(line)).await.is_err() {
+                                            break;
+                                        }
+                                    }
+                                }));
+                                detail = Some(DetailView::Logs(LogBuffer::new()));
+                            }
+                        }
+                        "inspect" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                detail = match client.inspect(&container.id).await {
+                                    Ok(text) => Some(DetailView::Inspect(text)),
+                                    Err(e) => {
+                                        status_message =
+                                            format!("Failed to inspect container: {e}");
+                                        None
+                                    }
+                                };
+                            }
+                        }
+                        "top" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                detail = match client.top(&container.id).await {
+                                    Ok(text) => Some(DetailView::Top(text)),
+                                    Err(e) => {
+                                        status_message = format!("Failed to list processes: {e}");
+                                        None
+                                    }
+                                };
+                            }
+                        }
+                        "stats" => {
+                            if let Some(container) = containers.get(selected_container) {
+                                detail = match client.stats(&container.id).await {
+                                    Ok(text) => Some(DetailView::Stats(text)),
+                                    Err(e) => {
+                                        status_message = format!("Failed to fetch stats: {e}");
+                                        None
+                                    }
+                                };
+                            }
+                        }
+                        _ => {}
+                    },
                     _ => {}
-                },
-                _ => {}
+                }
+                dirty = true;
             }
         }
-    }
 
-    Ok(())
-}
-
-async fn get_docker_ps_output(all: bool) -> Vec<ContainerInfo> {
-    let mut command = Command::new("docker");
-    command.arg("ps");
-    if all {
-        command.arg("-a");
+        if dirty {
+            terminal.draw(|f| {
+                draw_ui(
+                    f,
+                    &commands,
+                    selected_command,
+                    &containers,
+                    selected_container,
+                    &detail,
+                    &status_message,
+                    &pending_confirm,
+                    &registry,
+                )
+            })?;
+            dirty = false;
+        }
     }
-    command.arg("--format").arg("{{json .}}");
-
-    let output = command.output().await.expect("Failed to execute docker ps");
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let containers: Vec<ContainerInfo> = stdout
-        .lines()
-        .filter_map(|line| serde_json::from_str::<ContainerInfo>(line).ok())
-        .collect();
 
-    containers
+    Ok(())
 }
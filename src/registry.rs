@@ -0,0 +1,163 @@
+//! Docker Hub registry polling for "is a newer tag available" flags.
+//!
+//! Results are cached per image with a TTL so the table can flag stale
+//! containers without hammering the registry's anonymous rate limit. The
+//! actual HTTP lookup runs on a background task spawned by
+//! `BollardClient::check_for_update`, which reports back over a channel as
+//! an `AppEvent::RegistryResult`, so it never blocks the render loop.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const TTL: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateStatus {
+    Unknown,
+    UpToDate,
+    UpdateAvailable,
+}
+
+/// Per-image cache of the last registry check.
+pub struct RegistryChecker {
+    cache: HashMap<String, (Instant, UpdateStatus)>,
+}
+
+impl RegistryChecker {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn status(&self, image: &str) -> UpdateStatus {
+        self.cache
+            .get(image)
+            .map(|(_, status)| *status)
+            .unwrap_or(UpdateStatus::Unknown)
+    }
+
+    /// The first image among `images` whose cache entry is missing or has
+    /// expired, if any. Checked one at a time so a tick only ever issues a
+    /// single registry request.
+    pub fn stale_image<'a>(&self, images: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+        images.into_iter().find(|image| {
+            self.cache
+                .get(*image)
+                .map(|(checked_at, _)| checked_at.elapsed() > TTL)
+                .unwrap_or(true)
+        })
+    }
+
+    pub fn record(&mut self, image: String, status: UpdateStatus) {
+        self.cache.insert(image, (Instant::now(), status));
+    }
+}
+
+#[derive(Deserialize)]
+struct TagResponse {
+    images: Vec<TagImage>,
+}
+
+#[derive(Deserialize)]
+struct TagImage {
+    digest: Option<String>,
+}
+
+/// Looks up the digest Docker Hub has recorded for `image`'s tag, e.g.
+/// `nginx:latest` or `myuser/app:v2`. Returns `None` on any lookup failure
+/// (network error, private registry, unknown repository, ...).
+pub async fn registry_digest(http: &reqwest::Client, image: &str) -> Option<String> {
+    let (repo, tag) = split_image(image);
+    let url = format!("https://registry.hub.docker.com/v2/repositories/{repo}/tags/{tag}");
+    let response = http.get(url).send().await.ok()?;
+    let parsed: TagResponse = response.json().await.ok()?;
+    parsed.images.into_iter().find_map(|i| i.digest)
+}
+
+/// Splits `name:tag` into `(repository, tag)`, defaulting to the `latest`
+/// tag and the `library/` namespace for official images. The tag separator
+/// is looked for only in the final path segment, so a registry host with a
+/// port (e.g. `localhost:5000/myapp:latest`) isn't mistaken for a tag.
+fn split_image(image: &str) -> (String, String) {
+    let last_segment_start = image.rfind('/').map_or(0, |i| i + 1);
+    let (name, tag) = match image[last_segment_start..].find(':') {
+        Some(offset) => {
+            let colon = last_segment_start + offset;
+            (&image[..colon], &image[colon + 1..])
+        }
+        None => (image, "latest"),
+    };
+    let repo = if name.contains('/') {
+        name.to_string()
+    } else {
+        format!("library/{name}")
+    };
+    (repo, tag.to_string())
+}
+
+/// Compares two `sha256:...` digests, tolerating either with or without
+/// the scheme prefix.
+pub fn digests_match(local: &str, remote: &str) -> bool {
+    local.trim_start_matches("sha256:") == remote.trim_start_matches("sha256:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_image_defaults_to_latest_and_library_namespace() {
+        assert_eq!(
+            split_image("nginx"),
+            ("library/nginx".to_string(), "latest".to_string())
+        );
+    }
+
+    #[test]
+    fn split_image_splits_explicit_tag() {
+        assert_eq!(
+            split_image("nginx:1.25"),
+            ("library/nginx".to_string(), "1.25".to_string())
+        );
+    }
+
+    #[test]
+    fn split_image_keeps_user_namespace() {
+        assert_eq!(
+            split_image("myuser/app:v2"),
+            ("myuser/app".to_string(), "v2".to_string())
+        );
+    }
+
+    #[test]
+    fn split_image_ignores_port_colon_in_registry_host() {
+        assert_eq!(
+            split_image("localhost:5000/myapp:latest"),
+            ("localhost:5000/myapp".to_string(), "latest".to_string())
+        );
+    }
+
+    #[test]
+    fn split_image_defaults_tag_for_registry_host_with_no_tag() {
+        assert_eq!(
+            split_image("localhost:5000/myapp"),
+            ("localhost:5000/myapp".to_string(), "latest".to_string())
+        );
+    }
+
+    #[test]
+    fn digests_match_ignores_scheme_prefix() {
+        assert!(digests_match(
+            "sha256:abc123",
+            "sha256:abc123"
+        ));
+        assert!(digests_match("abc123", "sha256:abc123"));
+    }
+
+    #[test]
+    fn digests_match_detects_mismatch() {
+        assert!(!digests_match("sha256:abc123", "sha256:def456"));
+    }
+}